@@ -33,6 +33,143 @@ const CHARS: [&str; 128] = [
     "하", "한", "해", "허", "호", "홍", "화", "후", "히",
 ];
 
+/// Composes decomposed Hangul jamo (e.g. from some IMEs or copy-paste, in NFD form)
+/// into precomposed syllables, using the standard Unicode Hangul composition algorithm.
+/// Non-jamo code points are left untouched.
+fn compose_hangul_nfc(s: &str) -> String {
+    const S_BASE: u32 = 0xAC00;
+    const L_BASE: u32 = 0x1100;
+    const V_BASE: u32 = 0x1161;
+    const T_BASE: u32 = 0x11A7;
+    const L_COUNT: u32 = 19;
+    const V_COUNT: u32 = 21;
+    const T_COUNT: u32 = 28;
+    const N_COUNT: u32 = V_COUNT * T_COUNT;
+
+    let chars: Vec<char> = s.chars().collect();
+    let mut result = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let l = chars[i] as u32;
+        if (L_BASE..L_BASE + L_COUNT).contains(&l) && i + 1 < chars.len() {
+            let v = chars[i + 1] as u32;
+            if (V_BASE..V_BASE + V_COUNT).contains(&v) {
+                let l_index = l - L_BASE;
+                let v_index = v - V_BASE;
+                let mut s_index = l_index * N_COUNT + v_index * T_COUNT;
+                let mut consumed = 2;
+
+                if i + 2 < chars.len() {
+                    let t = chars[i + 2] as u32;
+                    if (T_BASE + 1..T_BASE + T_COUNT).contains(&t) {
+                        s_index += t - T_BASE;
+                        consumed = 3;
+                    }
+                }
+
+                result.push(char::from_u32(S_BASE + s_index).unwrap());
+                i += consumed;
+                continue;
+            }
+        }
+
+        result.push(chars[i]);
+        i += 1;
+    }
+
+    result
+}
+
+/// Converts a non-negative integer into base-128 digits, most significant first.
+/// Zero is represented as the single digit `[0]`.
+fn base128_digits(num: u128) -> Vec<usize> {
+    if num == 0 {
+        return vec![0];
+    }
+
+    let mut digits: Vec<usize> = Vec::new();
+    let mut temp = num;
+    while temp > 0 {
+        digits.push((temp % 128) as usize);
+        temp /= 128;
+    }
+    digits.reverse();
+    digits
+}
+
+// Sign markers for signed encodings: 덧 (from 덧셈, "addition") for positive and 뺄
+// (from 뺄셈, "subtraction") for negative. Both are precomposed Hangul syllables outside
+// the 128-entry CHARS table, so a sign marker can never be mistaken for a seed/digit
+// syllable, while keeping the "it's all Hangul" invariant the rest of the API promises
+// (decode_i128 matches these positionally, never through reverse_map, so they don't
+// need to be — and must not be — registered in CHARS).
+const SIGN_POSITIVE: &str = "덧";
+const SIGN_NEGATIVE: &str = "뺄";
+
+/// Converts a big-endian byte buffer into base-128 digits, most significant first.
+/// An all-zero (or empty) buffer yields no digits, since leading/whole zero bytes are
+/// represented separately as zero markers by the caller.
+fn bytes_be_to_base128_digits(bytes: &[u8]) -> Vec<usize> {
+    let mut big: Vec<u8> = bytes.to_vec();
+    while big.len() > 1 && big[0] == 0 {
+        big.remove(0);
+    }
+    if big.is_empty() || big.iter().all(|&b| b == 0) {
+        return Vec::new();
+    }
+
+    let mut digits: Vec<usize> = Vec::new();
+    while !(big.len() == 1 && big[0] == 0) {
+        let mut remainder: u32 = 0;
+        for byte in big.iter_mut() {
+            let acc = remainder * 256 + *byte as u32;
+            *byte = (acc / 128) as u8;
+            remainder = acc % 128;
+        }
+        digits.push(remainder as usize);
+        while big.len() > 1 && big[0] == 0 {
+            big.remove(0);
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+/// Computes a position-sensitive checksum over base-128 digits (most significant first),
+/// weighting each digit by its 1-based position so that transposing two adjacent distinct
+/// digits (a common typo/OCR error) changes the result, unlike a plain unweighted sum.
+/// Swapping digits that are further apart changes it by (a - b) * distance mod 128, which
+/// can be zero (e.g. digits differing by 64 that are two positions apart).
+fn weighted_checksum(digits: &[usize]) -> usize {
+    digits
+        .iter()
+        .enumerate()
+        .fold(0usize, |acc, (i, &d)| (acc + d * (i + 1)) % 128)
+}
+
+/// Converts base-128 digits (most significant first) back into a big-endian byte buffer,
+/// the inverse of [`bytes_be_to_base128_digits`]. An empty digit list yields an empty buffer.
+fn base128_digits_to_bytes(digits: &[usize]) -> Vec<u8> {
+    if digits.is_empty() {
+        return Vec::new();
+    }
+
+    let mut big: Vec<u8> = vec![0];
+    for &d in digits {
+        let mut carry = d as u32;
+        for b in big.iter_mut().rev() {
+            let acc = (*b as u32) * 128 + carry;
+            *b = (acc & 0xFF) as u8;
+            carry = acc >> 8;
+        }
+        while carry > 0 {
+            big.insert(0, (carry & 0xFF) as u8);
+            carry >>= 8;
+        }
+    }
+    big
+}
+
 /// Hangul Number Converter (Base-128, Variable Length)
 pub struct HangulNumberConverter {
     reverse_map: HashMap<String, usize>,
@@ -62,32 +199,29 @@ impl HangulNumberConverter {
     /// # Returns
     /// The encoded Hangul string (seed + scrambled data)
     pub fn encode_with_seed(&self, num: u64, seed: usize) -> Result<String, String> {
+        self.encode_u128_with_seed(num as u128, seed)
+    }
+
+    /// Encodes a non-negative `u128` into a variable-length Hangul string with a specific seed.
+    /// This lifts the `u64` ceiling of [`encode_with_seed`](Self::encode_with_seed) for
+    /// larger magnitudes.
+    ///
+    /// # Arguments
+    /// * `num` - The number to encode (must be >= 0)
+    /// * `seed` - The seed to use for scrambling (0~127)
+    ///
+    /// # Returns
+    /// The encoded Hangul string (seed + scrambled data)
+    pub fn encode_u128_with_seed(&self, num: u128, seed: usize) -> Result<String, String> {
         if seed >= 128 {
             return Err("Seed must be between 0 and 127".to_string());
         }
 
         let seed_char = CHARS[seed];
 
-        // Special case for 0
-        if num == 0 {
-            let scrambled = (0 + seed) % 128;
-            return Ok(format!("{}{}", seed_char, CHARS[scrambled]));
-        }
-
-        // Convert to base-128 digits (least significant first)
-        let mut digits: Vec<usize> = Vec::new();
-        let mut temp = num;
-        while temp > 0 {
-            digits.push((temp % 128) as usize);
-            temp /= 128;
-        }
-
-        // Reverse to get most significant first
-        digits.reverse();
-
-        // Scramble each digit with seed and map to Hangul
+        // Scramble each base-128 digit with seed and map to Hangul
         let mut result = String::from(seed_char);
-        for d in digits {
+        for d in base128_digits(num) {
             let scrambled = (d + seed) % 128;
             result.push_str(CHARS[scrambled]);
         }
@@ -116,6 +250,24 @@ impl HangulNumberConverter {
         self.encode_with_seed(num, seed)
     }
 
+    /// Encodes a non-negative `u128` into a variable-length Hangul string, using a random seed.
+    ///
+    /// # Arguments
+    /// * `num` - The number to encode (must be >= 0)
+    ///
+    /// # Returns
+    /// The encoded Hangul string (seed + scrambled data)
+    pub fn encode_u128(&self, num: u128) -> Result<String, String> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos() as usize % 128;
+
+        self.encode_u128_with_seed(num, seed)
+    }
+
     /// Returns all 128 possible encodings for a number.
     /// 
     /// # Arguments
@@ -131,17 +283,133 @@ impl HangulNumberConverter {
         Ok(results)
     }
 
+    /// Encodes a number with an appended checksum syllable so that a mistyped or
+    /// OCR-garbled character is rejected on decode instead of silently producing
+    /// a wrong number.
+    ///
+    /// # Arguments
+    /// * `num` - The number to encode (must be >= 0)
+    /// * `seed` - The seed to use for scrambling (0~127)
+    ///
+    /// # Returns
+    /// The encoded Hangul string (seed + scrambled data + checksum)
+    pub fn encode_checked_with_seed(&self, num: u64, seed: usize) -> Result<String, String> {
+        if seed >= 128 {
+            return Err("Seed must be between 0 and 127".to_string());
+        }
+
+        let mut result = self.encode_with_seed(num, seed)?;
+        let checksum = weighted_checksum(&base128_digits(num as u128));
+        result.push_str(CHARS[(checksum + seed) % 128]);
+
+        Ok(result)
+    }
+
+    /// Encodes a number with an appended checksum syllable, using a random seed.
+    ///
+    /// # Arguments
+    /// * `num` - The number to encode (must be >= 0)
+    ///
+    /// # Returns
+    /// The encoded Hangul string (seed + scrambled data + checksum)
+    pub fn encode_checked(&self, num: u64) -> Result<String, String> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos() as usize % 128;
+
+        self.encode_checked_with_seed(num, seed)
+    }
+
+    /// Decodes a checksum-protected Hangul string, verifying the trailing checksum
+    /// syllable before returning the number.
+    ///
+    /// # Arguments
+    /// * `s` - The Hangul string to decode (first char is seed, last char is checksum)
+    ///
+    /// # Returns
+    /// The decoded number, or an error if the string is malformed or the checksum
+    /// does not match (e.g. a mistyped or corrupted character).
+    pub fn decode_checked(&self, s: &str) -> Result<u64, String> {
+        let normalized = compose_hangul_nfc(s);
+        let chars: Vec<&str> = normalized.graphemes(true).collect();
+
+        if chars.len() < 3 {
+            return Err(
+                "Invalid string: must be at least 3 characters for a checksum-protected encoding"
+                    .to_string(),
+            );
+        }
+
+        let seed_char = chars[0];
+        let seed = *self
+            .reverse_map
+            .get(seed_char)
+            .ok_or_else(|| format!("Invalid seed character: {}", seed_char))?;
+
+        let checksum_char = chars[chars.len() - 1];
+        let checksum_scrambled = *self
+            .reverse_map
+            .get(checksum_char)
+            .ok_or_else(|| format!("Invalid character: {}", checksum_char))?;
+        let received_checksum = (checksum_scrambled + 128 - seed) % 128;
+
+        // Accumulate in u128 (like decode_u128) with checked arithmetic, so an
+        // oversized-but-syntactically-valid digit string is rejected with a clean Err
+        // instead of panicking (debug) or silently wrapping (release).
+        let mut num: u128 = 0;
+        let mut digits: Vec<usize> = Vec::new();
+        for &c in &chars[1..chars.len() - 1] {
+            let scrambled = *self
+                .reverse_map
+                .get(c)
+                .ok_or_else(|| format!("Invalid character: {}", c))?;
+            let original = (scrambled + 128 - seed) % 128;
+            digits.push(original);
+            num = num
+                .checked_mul(128)
+                .and_then(|n| n.checked_add(original as u128))
+                .ok_or_else(|| "Decoded value exceeds u128 range".to_string())?;
+        }
+
+        let computed_checksum = weighted_checksum(&digits);
+        if computed_checksum != received_checksum {
+            return Err("Checksum mismatch: the encoded string may be corrupted or mistyped".to_string());
+        }
+
+        u64::try_from(num).map_err(|_| "Decoded value exceeds u64 range".to_string())
+    }
+
     /// Decodes a Hangul string back to a number.
-    /// 
+    ///
     /// # Arguments
     /// * `s` - The Hangul string to decode (first char is seed)
-    /// 
+    ///
     /// # Returns
     /// The decoded number
     pub fn decode(&self, s: &str) -> Result<u64, String> {
+        let num = self.decode_u128(s)?;
+        u64::try_from(num).map_err(|_| "Decoded value exceeds u64 range".to_string())
+    }
+
+    /// Decodes a Hangul string back to a `u128`, lifting the `u64` ceiling of
+    /// [`decode`](Self::decode) for larger magnitudes.
+    ///
+    /// # Arguments
+    /// * `s` - The Hangul string to decode (first char is seed)
+    ///
+    /// # Returns
+    /// The decoded number
+    pub fn decode_u128(&self, s: &str) -> Result<u128, String> {
+        // Normalize decomposed jamo (NFD) into precomposed syllables (NFC) so IME/copy-paste
+        // input matches the same syllables as the curated CHARS table.
+        let normalized = compose_hangul_nfc(s);
+
         // Get grapheme clusters (each Hangul character is one grapheme)
-        let chars: Vec<&str> = s.graphemes(true).collect();
-        
+        let chars: Vec<&str> = normalized.graphemes(true).collect();
+
         if chars.len() < 2 {
             return Err("Invalid string: must be at least 2 characters".to_string());
         }
@@ -152,19 +420,208 @@ impl HangulNumberConverter {
             .get(seed_char)
             .ok_or_else(|| format!("Invalid seed character: {}", seed_char))?;
 
-        // Decode remaining characters
-        let mut num: u64 = 0;
+        // Decode remaining characters, with checked arithmetic so an oversized-but-valid
+        // digit string is rejected with an Err instead of panicking (debug) or silently
+        // wrapping (release).
+        let mut num: u128 = 0;
         for &c in &chars[1..] {
             let scrambled = self.reverse_map
                 .get(c)
                 .ok_or_else(|| format!("Invalid character: {}", c))?;
             // Unscramble: original = (scrambled - seed + 128) % 128
             let original = (*scrambled + 128 - seed) % 128;
-            num = num * 128 + original as u64;
+            num = num
+                .checked_mul(128)
+                .and_then(|n| n.checked_add(original as u128))
+                .ok_or_else(|| "Decoded value exceeds u128 range".to_string())?;
         }
 
         Ok(num)
     }
+
+    /// Encodes a signed integer into a variable-length Hangul string with a specific seed.
+    /// A sign syllable (덧 for positive, 뺄 for negative), which can never collide with a
+    /// `CHARS` syllable, follows the seed so positive and negative numbers produce distinct
+    /// prefixes.
+    ///
+    /// # Arguments
+    /// * `num` - The number to encode
+    /// * `seed` - The seed to use for scrambling (0~127)
+    ///
+    /// # Returns
+    /// The encoded Hangul string (seed + sign + scrambled data)
+    pub fn encode_i128_with_seed(&self, num: i128, seed: usize) -> Result<String, String> {
+        let sign = if num >= 0 { SIGN_POSITIVE } else { SIGN_NEGATIVE };
+        let magnitude = num.unsigned_abs();
+        let body = self.encode_u128_with_seed(magnitude, seed)?;
+        let seed_char = CHARS[seed];
+        let digits = &body[seed_char.len()..];
+
+        Ok(format!("{}{}{}", seed_char, sign, digits))
+    }
+
+    /// Encodes a signed integer into a variable-length Hangul string, using a random seed.
+    ///
+    /// # Arguments
+    /// * `num` - The number to encode
+    ///
+    /// # Returns
+    /// The encoded Hangul string (seed + sign + scrambled data)
+    pub fn encode_i128(&self, num: i128) -> Result<String, String> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos() as usize % 128;
+
+        self.encode_i128_with_seed(num, seed)
+    }
+
+    /// Decodes a signed Hangul string back into an `i128`.
+    ///
+    /// # Arguments
+    /// * `s` - The Hangul string to decode (first char is seed, second is the sign syllable)
+    ///
+    /// # Returns
+    /// The decoded signed number
+    pub fn decode_i128(&self, s: &str) -> Result<i128, String> {
+        let normalized = compose_hangul_nfc(s);
+        let chars: Vec<&str> = normalized.graphemes(true).collect();
+
+        if chars.len() < 3 {
+            return Err("Invalid string: must be at least 3 characters for a signed number".to_string());
+        }
+
+        let is_negative = match chars[1] {
+            SIGN_POSITIVE => false,
+            SIGN_NEGATIVE => true,
+            other => return Err(format!("Invalid sign character: {}", other)),
+        };
+
+        let body: String = std::iter::once(chars[0]).chain(chars[2..].iter().copied()).collect();
+        let magnitude = self.decode_u128(&body)?;
+
+        if is_negative {
+            if magnitude == 0 {
+                return Err("Negative zero is not a valid encoding".to_string());
+            }
+            if magnitude == i128::MIN.unsigned_abs() {
+                Ok(i128::MIN)
+            } else if magnitude < i128::MIN.unsigned_abs() {
+                Ok(-(magnitude as i128))
+            } else {
+                Err("Magnitude out of range for i128".to_string())
+            }
+        } else if magnitude <= i128::MAX as u128 {
+            Ok(magnitude as i128)
+        } else {
+            Err("Magnitude out of range for i128".to_string())
+        }
+    }
+
+    /// Encodes an arbitrary byte buffer into a Hangul string, treating it as a big-endian
+    /// big integer (a base-128-over-bytes scheme, similar to base58-over-bytes). Leading
+    /// `0x00` bytes are preserved exactly: each one is emitted as a fixed zero syllable
+    /// before the numeric payload, rather than being silently dropped.
+    ///
+    /// # Arguments
+    /// * `bytes` - The byte buffer to encode
+    /// * `seed` - The seed to use for scrambling (0~127)
+    ///
+    /// # Returns
+    /// The encoded Hangul string (seed + zero markers + scrambled numeric payload)
+    pub fn encode_bytes_with_seed(&self, bytes: &[u8], seed: usize) -> Result<String, String> {
+        if seed >= 128 {
+            return Err("Seed must be between 0 and 127".to_string());
+        }
+
+        let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+        let rest = &bytes[leading_zeros..];
+
+        let mut result = String::from(CHARS[seed]);
+        let zero_scrambled = seed;
+        for _ in 0..leading_zeros {
+            result.push_str(CHARS[zero_scrambled]);
+        }
+
+        for d in bytes_be_to_base128_digits(rest) {
+            result.push_str(CHARS[(d + seed) % 128]);
+        }
+
+        Ok(result)
+    }
+
+    /// Encodes an arbitrary byte buffer into a Hangul string, using a random seed.
+    ///
+    /// # Arguments
+    /// * `bytes` - The byte buffer to encode
+    ///
+    /// # Returns
+    /// The encoded Hangul string (seed + zero markers + scrambled numeric payload)
+    pub fn encode_bytes(&self, bytes: &[u8]) -> Result<String, String> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .subsec_nanos() as usize % 128;
+
+        self.encode_bytes_with_seed(bytes, seed)
+    }
+
+    /// Decodes a Hangul string produced by [`encode_bytes`](Self::encode_bytes) back into
+    /// its original byte buffer, reconstructing any preserved leading zero bytes.
+    ///
+    /// # Arguments
+    /// * `s` - The Hangul string to decode (first char is seed)
+    ///
+    /// # Returns
+    /// The decoded byte buffer
+    pub fn decode_bytes(&self, s: &str) -> Result<Vec<u8>, String> {
+        let normalized = compose_hangul_nfc(s);
+        let chars: Vec<&str> = normalized.graphemes(true).collect();
+
+        if chars.is_empty() {
+            return Err("Invalid string: empty".to_string());
+        }
+
+        let seed_char = chars[0];
+        let seed = *self
+            .reverse_map
+            .get(seed_char)
+            .ok_or_else(|| format!("Invalid seed character: {}", seed_char))?;
+
+        // Leading zero-byte markers sort before the numeric payload, whose most
+        // significant base-128 digit is never zero (no leading-zero digits), so the
+        // run of zero markers is unambiguous, the same way base58check's zero prefix is.
+        let zero_scrambled = seed;
+        let mut payload_start = 1;
+        while payload_start < chars.len() {
+            let scrambled = *self
+                .reverse_map
+                .get(chars[payload_start])
+                .ok_or_else(|| format!("Invalid character: {}", chars[payload_start]))?;
+            if scrambled != zero_scrambled {
+                break;
+            }
+            payload_start += 1;
+        }
+        let leading_zeros = payload_start - 1;
+
+        let mut digits: Vec<usize> = Vec::new();
+        for &c in &chars[payload_start..] {
+            let scrambled = *self
+                .reverse_map
+                .get(c)
+                .ok_or_else(|| format!("Invalid character: {}", c))?;
+            digits.push((scrambled + 128 - seed) % 128);
+        }
+
+        let mut result = vec![0u8; leading_zeros];
+        result.extend(base128_digits_to_bytes(&digits));
+        Ok(result)
+    }
 }
 
 impl Default for HangulNumberConverter {
@@ -173,18 +630,212 @@ impl Default for HangulNumberConverter {
     }
 }
 
+// Sino-Korean digit words (index == digit, 0 is only used for the standalone "영").
+const SINO_DIGITS: [&str; 10] = [
+    "영", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구",
+];
+// Sino-Korean place words within a single 4-digit chunk, indexed by decimal place (units..thousands).
+const SINO_CHUNK_PLACES: [&str; 4] = ["", "십", "백", "천"];
+// Sino-Korean myriad (10^4) group words, indexed by group (units group has none).
+const SINO_MYRIAD_PLACES: [&str; 5] = ["", "만", "억", "조", "경"];
+
+// Pure-Korean (native) ones and tens words, valid for 1..=99.
+const PURE_ONES: [&str; 10] = [
+    "", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+const PURE_TENS: [&str; 10] = [
+    "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+/// Spells a single 1..=9999 chunk as Sino-Korean, omitting "일" before 십/백/천.
+fn sino_chunk_to_words(chunk: u32) -> String {
+    let digits = [chunk / 1000 % 10, chunk / 100 % 10, chunk / 10 % 10, chunk % 10];
+    let mut s = String::new();
+    for (place, &digit) in SINO_CHUNK_PLACES.iter().rev().zip(digits.iter()) {
+        if digit == 0 {
+            continue;
+        }
+        if digit == 1 && !place.is_empty() {
+            s.push_str(place);
+        } else {
+            s.push_str(SINO_DIGITS[digit as usize]);
+            s.push_str(place);
+        }
+    }
+    s
+}
+
+impl HangulNumberConverter {
+    /// Spells a number as Korean numerals, either Sino-Korean (한자어) or Pure-Korean (고유어).
+    ///
+    /// # Arguments
+    /// * `num` - The number to spell out
+    /// * `is_sino` - `true` for Sino-Korean (일, 이, 삼 ...), `false` for Pure-Korean (하나, 둘, 셋 ...)
+    ///
+    /// # Returns
+    /// The Korean spelling of `num`. Pure-Korean only covers 1..=99 natively; zero is "영"
+    /// in both modes, and numbers above 99 fall back to the Sino-Korean spelling.
+    pub fn to_korean_words(&self, num: u64, is_sino: bool) -> Result<String, String> {
+        if num == 0 {
+            return Ok("영".to_string());
+        }
+
+        if !is_sino {
+            if num > 99 {
+                return self.to_korean_words(num, true);
+            }
+            let num = num as u32;
+            let mut s = String::new();
+            s.push_str(PURE_TENS[(num / 10) as usize]);
+            s.push_str(PURE_ONES[(num % 10) as usize]);
+            return Ok(s);
+        }
+
+        // Split into 4-digit myriad groups, least significant first.
+        let mut groups: Vec<u32> = Vec::new();
+        let mut n = num;
+        while n > 0 {
+            groups.push((n % 10000) as u32);
+            n /= 10000;
+        }
+
+        let mut result = String::new();
+        for (group_index, &group) in groups.iter().enumerate().rev() {
+            if group == 0 {
+                continue;
+            }
+            let place = SINO_MYRIAD_PLACES[group_index];
+            // "일" is only dropped before 만 (만, not 일만); 억/조/경 keep it (일억, 일조, 일경).
+            if group == 1 && group_index == 1 {
+                result.push_str(place);
+            } else {
+                result.push_str(&sino_chunk_to_words(group));
+                result.push_str(place);
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Grapheme_Cluster_Break property values used by the UAX #29 boundary rules below.
+/// Covers the categories needed for CR/LF, Hangul, and mark/ZWJ/regional-indicator
+/// handling. `SpacingMark` and `Prepend` (relevant mostly to Indic scripts) are not
+/// classified separately and fall back to `Other`, so GB9a/GB9b are not applied.
+/// `Extend` is recognised only for the code point ranges listed in [`is_extend`], and
+/// there is no Extended_Pictographic class, so GB11 is applied in simplified form: a ZWJ
+/// is joined to whatever non-control character follows it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GraphemeCat {
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    RegionalIndicator,
+    Other,
+}
+
+fn is_hangul_l(cp: u32) -> bool {
+    (0x1100..=0x115F).contains(&cp) || (0xA960..=0xA97C).contains(&cp)
+}
+
+fn is_hangul_v(cp: u32) -> bool {
+    (0x1160..=0x11A7).contains(&cp) || (0xD7B0..=0xD7C6).contains(&cp)
+}
+
+fn is_hangul_t(cp: u32) -> bool {
+    (0x11A8..=0x11FF).contains(&cp) || (0xD7CB..=0xD7FB).contains(&cp)
+}
+
+/// `true` for code points that attach to the preceding character as a combining mark
+/// (Grapheme_Cluster_Break=Extend), rather than starting a new user-perceived character.
+fn is_extend(cp: u32) -> bool {
+    matches!(cp,
+        0x0300..=0x036F   // Combining Diacritical Marks
+        | 0x0483..=0x0489
+        | 0x0591..=0x05BD | 0x05BF | 0x05C1 | 0x05C2 | 0x05C4 | 0x05C5 | 0x05C7
+        | 0x0610..=0x061A
+        | 0x064B..=0x065F | 0x0670
+        | 0x1AB0..=0x1AFF // Combining Diacritical Marks Extended
+        | 0x1DC0..=0x1DFF // Combining Diacritical Marks Supplement
+        | 0x20D0..=0x20FF // Combining Diacritical Marks for Symbols
+        | 0xFE00..=0xFE0F // Variation Selectors
+        | 0xFE20..=0xFE2F // Combining Half Marks
+        | 0xE0100..=0xE01EF // Variation Selectors Supplement
+    )
+}
+
+fn grapheme_category(c: char) -> GraphemeCat {
+    let cp = c as u32;
+    match c {
+        '\r' => GraphemeCat::Cr,
+        '\n' => GraphemeCat::Lf,
+        '\u{200D}' => GraphemeCat::Zwj,
+        _ if (0x1F1E6..=0x1F1FF).contains(&cp) => GraphemeCat::RegionalIndicator,
+        _ if (0xAC00..=0xD7A3).contains(&cp) => {
+            // Precomposed syllable: LV if it has no trailing consonant, else LVT.
+            if (cp - 0xAC00).is_multiple_of(28) {
+                GraphemeCat::Lv
+            } else {
+                GraphemeCat::Lvt
+            }
+        }
+        _ if is_hangul_l(cp) => GraphemeCat::L,
+        _ if is_hangul_v(cp) => GraphemeCat::V,
+        _ if is_hangul_t(cp) => GraphemeCat::T,
+        _ if is_extend(cp) => GraphemeCat::Extend,
+        _ if c.is_control() => GraphemeCat::Control,
+        _ => GraphemeCat::Other,
+    }
+}
+
+/// Applies the UAX #29 extended grapheme cluster boundary rules (GB3-GB9, GB11-GB13;
+/// see [`GraphemeCat`] for the limits on GB9a/GB9b, `Extend` and GB11) to decide whether there is
+/// a cluster boundary between a character of category `prev` and one of category `cur`.
+/// `prev_ri_run` is the number of consecutive Regional_Indicator characters accumulated
+/// in the current cluster so far (used by GB12/GB13 to pair flag sequences).
+fn is_grapheme_boundary(prev: GraphemeCat, prev_ri_run: usize, cur: GraphemeCat) -> bool {
+    use GraphemeCat::*;
+    match (prev, cur) {
+        (Cr, Lf) => false,                                        // GB3
+        (Cr, _) | (Lf, _) | (Control, _) => true,                 // GB4
+        (_, Cr) | (_, Lf) | (_, Control) => true,                 // GB5
+        (L, L) | (L, V) | (L, Lv) | (L, Lvt) => false,            // GB6
+        (Lv, V) | (Lv, T) | (V, V) | (V, T) => false,             // GB7
+        (Lvt, T) | (T, T) => false,                               // GB8
+        (_, Extend) | (_, Zwj) => false,                          // GB9
+        (Zwj, _) => false,                                        // simplified GB11
+        (RegionalIndicator, RegionalIndicator) => prev_ri_run.is_multiple_of(2), // GB12/GB13
+        _ => true,                                                // GB999
+    }
+}
+
+/// Returns the extended grapheme clusters (user-perceived characters) of `s`, per the
+/// Unicode UAX #29 default boundary rules within the limits described on [`GraphemeCat`],
+/// so that e.g. decomposed Hangul jamo (가 as
+/// L+V), a base character followed by combining marks, a CRLF pair, or a ZWJ sequence
+/// are each treated as one "character" instead of splitting on raw `char` boundaries.
+pub fn graphemes(s: &str) -> GraphemeIterator<'_> {
+    GraphemeIterator { s, pos: 0 }
+}
+
 /// Trait for grapheme iteration (simplified version)
 trait Graphemes {
-    fn graphemes(&self, extended: bool) -> GraphemeIterator;
+    fn graphemes(&self, extended: bool) -> GraphemeIterator<'_>;
 }
 
 impl Graphemes for str {
-    fn graphemes(&self, _extended: bool) -> GraphemeIterator {
-        GraphemeIterator { s: self, pos: 0 }
+    fn graphemes(&self, _extended: bool) -> GraphemeIterator<'_> {
+        self::graphemes(self)
     }
 }
 
-struct GraphemeIterator<'a> {
+pub struct GraphemeIterator<'a> {
     s: &'a str,
     pos: usize,
 }
@@ -198,14 +849,25 @@ impl<'a> Iterator for GraphemeIterator<'a> {
         }
 
         let start = self.pos;
-        let mut chars = self.s[start..].chars();
-        
-        if let Some(c) = chars.next() {
-            self.pos += c.len_utf8();
-            Some(&self.s[start..self.pos])
-        } else {
-            None
+        let mut chars = self.s[start..].char_indices();
+        let (_, first) = chars.next()?;
+        let mut end = start + first.len_utf8();
+        let mut prev_cat = grapheme_category(first);
+        let mut ri_run = if prev_cat == GraphemeCat::RegionalIndicator { 1 } else { 0 };
+
+        while let Some(c) = self.s[end..].chars().next() {
+            let cur_cat = grapheme_category(c);
+            if is_grapheme_boundary(prev_cat, ri_run, cur_cat) {
+                break;
+            }
+
+            ri_run = if cur_cat == GraphemeCat::RegionalIndicator { ri_run + 1 } else { 0 };
+            prev_cat = cur_cat;
+            end += c.len_utf8();
         }
+
+        self.pos = end;
+        Some(&self.s[start..end])
     }
 }
 
@@ -339,4 +1001,283 @@ mod tests {
         assert!(converter.decode("가").is_err()); // Too short
         assert!(converter.decode("").is_err()); // Empty
     }
+
+    #[test]
+    fn test_encode_decode_u128_roundtrip() {
+        let converter = HangulNumberConverter::new();
+        let test_values: Vec<u128> = vec![0, 1, 127, u64::MAX as u128, u128::MAX];
+
+        for num in test_values {
+            for seed in [0usize, 1, 42, 127] {
+                let encoded = converter.encode_u128_with_seed(num, seed).unwrap();
+                let decoded = converter.decode_u128(&encoded).unwrap();
+                assert_eq!(decoded, num, "Failed for num={}, seed={}", num, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_u128_rejects_oversized_digit_string_without_panicking() {
+        let converter = HangulNumberConverter::new();
+        let seed = 1;
+
+        // 40 non-zero digit syllables overflows u128 (which fits at most ~19 base-128
+        // digits), but every syllable is still a syntactically valid CHARS entry.
+        let mut encoded = String::from(CHARS[seed]);
+        for _ in 0..40 {
+            encoded.push_str(CHARS[(1 + seed) % 128]);
+        }
+
+        assert!(converter.decode_u128(&encoded).is_err());
+        assert!(converter.decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_encode_decode_i128_roundtrip_against_all_seeds() {
+        let converter = HangulNumberConverter::new();
+        let test_values: Vec<i128> = vec![0, 1, -1, i128::MAX, i128::MIN, i64::MIN as i128];
+
+        for num in test_values {
+            for seed in 0..128 {
+                let encoded = converter.encode_i128_with_seed(num, seed).unwrap();
+                let decoded = converter.decode_i128(&encoded).unwrap();
+                assert_eq!(decoded, num, "Failed for num={}, seed={}", num, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_i128_sign_produces_distinct_prefixes() {
+        let converter = HangulNumberConverter::new();
+        let positive = converter.encode_i128_with_seed(42, 3).unwrap();
+        let negative = converter.encode_i128_with_seed(-42, 3).unwrap();
+        assert_ne!(positive, negative);
+        assert_eq!(converter.decode_i128(&positive).unwrap(), 42);
+        assert_eq!(converter.decode_i128(&negative).unwrap(), -42);
+    }
+
+    #[test]
+    fn test_i128_sign_marker_does_not_collide_with_chars_table() {
+        // CHARS does not contain 덧 or 뺄, so a sign marker can never be mistaken
+        // for a seed or digit syllable, even at a seed whose CHARS entry looks similar
+        // to a Korean sign word (e.g. seed 66 -> CHARS[66] == "양").
+        let converter = HangulNumberConverter::new();
+        assert!(!CHARS.contains(&"덧"));
+        assert!(!CHARS.contains(&"뺄"));
+
+        let encoded = converter.encode_i128_with_seed(1, 66).unwrap();
+        assert_eq!(converter.decode_i128(&encoded).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_u64_methods_are_thin_wrappers_over_u128() {
+        let converter = HangulNumberConverter::new();
+        let encoded_u64 = converter.encode_with_seed(12345, 7).unwrap();
+        let encoded_u128 = converter.encode_u128_with_seed(12345u128, 7).unwrap();
+        assert_eq!(encoded_u64, encoded_u128);
+        assert_eq!(converter.decode(&encoded_u64).unwrap(), 12345u64);
+    }
+
+    #[test]
+    fn test_encode_decode_bytes_roundtrip() {
+        let converter = HangulNumberConverter::new();
+        let test_buffers: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![0x01],
+            vec![0xFF, 0x00, 0xAB],
+            vec![0x00, 0x00, 0x01],
+            b"hello, hangul!".to_vec(),
+        ];
+
+        for buf in test_buffers {
+            for seed in [0usize, 1, 42, 127] {
+                let encoded = converter.encode_bytes_with_seed(&buf, seed).unwrap();
+                let decoded = converter.decode_bytes(&encoded).unwrap();
+                assert_eq!(decoded, buf, "Failed for buf={:?}, seed={}", buf, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_decode_bytes_all_zero() {
+        let converter = HangulNumberConverter::new();
+        let buf = vec![0x00, 0x00, 0x00];
+        let encoded = converter.encode_bytes_with_seed(&buf, 5).unwrap();
+        assert_eq!(converter.decode_bytes(&encoded).unwrap(), buf);
+    }
+
+    #[test]
+    fn test_graphemes_groups_combining_marks_with_base_char() {
+        // "가" followed by a combining acute accent (U+0301) is one grapheme cluster.
+        let s = "가\u{0301}나";
+        let clusters: Vec<&str> = graphemes(s).collect();
+        assert_eq!(clusters, vec!["가\u{0301}", "나"]);
+    }
+
+    #[test]
+    fn test_graphemes_treats_crlf_as_one_cluster() {
+        let clusters: Vec<&str> = graphemes("가\r\n나").collect();
+        assert_eq!(clusters, vec!["가", "\r\n", "나"]);
+    }
+
+    #[test]
+    fn test_graphemes_joins_decomposed_hangul_lv() {
+        // GB6: an L jamo immediately followed by a V jamo is one cluster ("가" in NFD).
+        let s = "\u{1100}\u{1161}";
+        let clusters: Vec<&str> = graphemes(s).collect();
+        assert_eq!(clusters, vec![s]);
+    }
+
+    #[test]
+    fn test_graphemes_joins_decomposed_hangul_lvt() {
+        // GB6/GB8: L + V + T jamo all join into one cluster ("각" in NFD).
+        let s = "\u{1100}\u{1161}\u{11A8}";
+        let clusters: Vec<&str> = graphemes(s).collect();
+        assert_eq!(clusters, vec![s]);
+    }
+
+    #[test]
+    fn test_graphemes_joins_zwj_sequence() {
+        // GB9/GB11: a ZWJ-joined sequence is one cluster, not split at each code point.
+        let s = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let clusters: Vec<&str> = graphemes(s).collect();
+        assert_eq!(clusters, vec![s]);
+    }
+
+    #[test]
+    fn test_graphemes_pairs_regional_indicators_into_flags() {
+        // GB12/GB13: two flag-emoji RI pairs stay as two clusters, not four or one.
+        let s = "\u{1F1FA}\u{1F1F8}\u{1F1EF}\u{1F1F5}"; // "US" + "JP" regional indicators
+        let clusters: Vec<&str> = graphemes(s).collect();
+        assert_eq!(clusters, vec!["\u{1F1FA}\u{1F1F8}", "\u{1F1EF}\u{1F1F5}"]);
+    }
+
+    #[test]
+    fn test_decode_accepts_decomposed_nfd_input() {
+        let converter = HangulNumberConverter::new();
+        let encoded = converter.encode_with_seed(12345, 7).unwrap();
+
+        // Manually decompose each syllable into its L/V(/T) jamo, as some IMEs produce.
+        let decomposed: String = encoded
+            .chars()
+            .map(|c| {
+                let s_index = c as u32 - 0xAC00;
+                let l = 0x1100 + s_index / 588;
+                let v = 0x1161 + (s_index % 588) / 28;
+                let t = s_index % 28;
+                let mut out = String::new();
+                out.push(char::from_u32(l).unwrap());
+                out.push(char::from_u32(v).unwrap());
+                if t > 0 {
+                    out.push(char::from_u32(0x11A7 + t).unwrap());
+                }
+                out
+            })
+            .collect();
+
+        assert_eq!(converter.decode(&decomposed).unwrap(), 12345);
+    }
+
+    #[test]
+    fn test_encode_decode_checked_roundtrip() {
+        let converter = HangulNumberConverter::new();
+        let test_values: Vec<u64> = vec![0, 1, 127, 128, 1000, 16384, u64::MAX / 2];
+
+        for num in test_values {
+            for seed in 0..128 {
+                let encoded = converter.encode_checked_with_seed(num, seed).unwrap();
+                let decoded = converter.decode_checked(&encoded).unwrap();
+                assert_eq!(decoded, num, "Failed for num={}, seed={}", num, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_checked_rejects_corruption() {
+        let converter = HangulNumberConverter::new();
+        let encoded = converter.encode_checked_with_seed(12345, 7).unwrap();
+
+        // Flip one data syllable to a different valid Hangul syllable from CHARS.
+        let mut chars: Vec<&str> = encoded.graphemes(true).collect();
+        let corrupt_idx = 1;
+        chars[corrupt_idx] = if chars[corrupt_idx] == "가" { "나" } else { "가" };
+        let corrupted: String = chars.concat();
+
+        assert!(converter.decode_checked(&corrupted).is_err());
+    }
+
+    #[test]
+    fn test_decode_checked_rejects_transposed_digits() {
+        // 6410 has at least two distinct middle digit syllables to swap; an unweighted
+        // sum checksum is blind to this and would decode it as a different, wrong number.
+        let converter = HangulNumberConverter::new();
+        let encoded = converter.encode_checked_with_seed(6410, 7).unwrap();
+
+        let mut chars: Vec<&str> = encoded.graphemes(true).collect();
+        chars.swap(1, 2);
+        let transposed: String = chars.concat();
+
+        assert!(converter.decode_checked(&transposed).is_err());
+    }
+
+    #[test]
+    fn test_decode_checked_too_short() {
+        let converter = HangulNumberConverter::new();
+        assert!(converter.decode_checked("가나").is_err());
+    }
+
+    #[test]
+    fn test_decode_checked_rejects_over_u64_magnitude_without_panicking() {
+        let converter = HangulNumberConverter::new();
+        let seed = 7;
+        let over_u64 = u64::MAX as u128 + 1000;
+
+        let body = converter.encode_u128_with_seed(over_u64, seed).unwrap();
+        let checksum = weighted_checksum(&base128_digits(over_u64));
+        let encoded = format!("{}{}", body, CHARS[(checksum + seed) % 128]);
+
+        assert!(converter.decode_checked(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_decode_checked_rejects_oversized_digit_string_without_panicking() {
+        let converter = HangulNumberConverter::new();
+        let seed = 1;
+
+        // 40 non-zero data syllables overflows u128 well before the checksum is even
+        // checked, but every syllable is still a syntactically valid CHARS entry.
+        let mut encoded = String::from(CHARS[seed]);
+        for _ in 0..40 {
+            encoded.push_str(CHARS[(1 + seed) % 128]);
+        }
+        encoded.push_str(CHARS[seed]);
+
+        assert!(converter.decode_checked(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_sino_korean_words() {
+        let converter = HangulNumberConverter::new();
+        assert_eq!(converter.to_korean_words(0, true).unwrap(), "영");
+        assert_eq!(converter.to_korean_words(1, true).unwrap(), "일");
+        assert_eq!(converter.to_korean_words(10, true).unwrap(), "십");
+        assert_eq!(converter.to_korean_words(11, true).unwrap(), "십일");
+        assert_eq!(converter.to_korean_words(123, true).unwrap(), "백이십삼");
+        assert_eq!(converter.to_korean_words(10000, true).unwrap(), "만");
+        assert_eq!(converter.to_korean_words(20000, true).unwrap(), "이만");
+        // "일" is dropped only before 만; 억/조 keep it (일억, 일조).
+        assert_eq!(converter.to_korean_words(100000000, true).unwrap(), "일억");
+        assert_eq!(converter.to_korean_words(1000000000000, true).unwrap(), "일조");
+    }
+
+    #[test]
+    fn test_pure_korean_words() {
+        let converter = HangulNumberConverter::new();
+        assert_eq!(converter.to_korean_words(1, false).unwrap(), "하나");
+        assert_eq!(converter.to_korean_words(10, false).unwrap(), "열");
+        assert_eq!(converter.to_korean_words(21, false).unwrap(), "스물하나");
+        assert_eq!(converter.to_korean_words(99, false).unwrap(), "아흔아홉");
+        // Above the native range, falls back to Sino-Korean.
+        assert_eq!(converter.to_korean_words(100, false).unwrap(), "백");
+    }
 }